@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+
+#[derive(Default)]
+pub struct LastTailPosition(pub Option<Position>);
+
+#[derive(Default)]
+pub struct SnakeSegments(pub Vec<Entity>);
+
+/// Governs the rules of the arena: whether the snake wraps around the edges
+/// or dies on collision with a wall, and how large the arena is.
+pub struct GameRules {
+    pub wrap_walls: bool,
+    pub arena_width: u32,
+    pub arena_height: u32,
+}
+
+pub struct GrowthEvent;
+pub struct GameOverEvent;
+pub struct GameWonEvent;
+
+#[derive(PartialEq, Copy, Clone)]
+pub enum Direction {
+    Left,
+    Up,
+    Right,
+    Down,
+}
+
+impl Direction {
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub struct Size {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Size {
+    pub fn square(x: f32) -> Size {
+        Size {
+            width: x,
+            height: x,
+        }
+    }
+}
+
+pub struct Materials {
+    pub head_material: Handle<ColorMaterial>,
+    pub food_material: Handle<ColorMaterial>,
+    pub body_material: Handle<ColorMaterial>,
+}
+
+pub struct SnakeHead {
+    pub direction: Direction,
+    pub intention: Direction,
+}
+
+pub struct SnakeBody;
+pub struct Food;
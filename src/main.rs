@@ -1,75 +1,17 @@
 #![windows_subsystem = "windows"]
 
-use bevy::core::FixedTimestep;
 use bevy::prelude::*;
 use bevy::render::pass::ClearColor;
-use rand::prelude::random;
 
-const ARENA_WIDTH: u32 = 10;
-const ARENA_HEIGHT: u32 = 10;
+mod components;
+mod plugin;
+mod systems;
 
-#[derive(SystemLabel, Debug, Hash, PartialEq, Eq, Clone)]
-pub enum SnakeMovement {
-    Input,
-    Movement,
-    Eating,
-    Growing,
-}
-
-#[derive(Default)]
-struct LastTailPosition(Option<Position>);
-
-#[derive(Default)]
-struct SnakeSegments(Vec<Entity>);
-
-struct GrowthEvent;
-struct GameOverEvent;
-
-#[derive(PartialEq, Copy, Clone)]
-enum Direction {
-    Left,
-    Up,
-    Right,
-    Down,
-}
-
-#[derive(Default, Copy, Clone, Eq, PartialEq, Hash)]
-struct Position {
-    x: i32,
-    y: i32,
-}
-
-struct Size {
-    width: f32,
-    height: f32,
-}
-
-impl Size {
-    pub fn square(x: f32) -> Size {
-        Size {
-            width: x,
-            height: x,
-        }
-    }
-}
-
-struct Materials {
-    head_material: Handle<ColorMaterial>,
-    food_material: Handle<ColorMaterial>,
-    body_material: Handle<ColorMaterial>,
-}
-
-struct SnakeHead {
-    direction: Direction,
-}
-
-struct SnakeBody;
-struct Food;
+use plugin::SnakeGamePlugin;
+use systems::get_bevy_color;
 
 fn main() {
     App::build()
-        .insert_resource(LastTailPosition::default())
-        .insert_resource(SnakeSegments::default())
         .insert_resource(ClearColor(get_bevy_color(40, 40, 40)))
         .insert_resource(WindowDescriptor {
             title: "Snake!".to_string(),
@@ -78,255 +20,7 @@ fn main() {
             resizable: false,
             ..Default::default()
         })
-        .add_event::<GrowthEvent>()
-        .add_event::<GameOverEvent>()
-        .add_startup_system(setup.system())
-        .add_startup_stage("game_setup", SystemStage::single(spawn_snake.system()))
-        .add_system(
-            snake_movement_input
-                .system()
-                .label(SnakeMovement::Input)
-                .before(SnakeMovement::Movement),
-        )
-        .add_system_set(
-            SystemSet::new()
-                .with_run_criteria(FixedTimestep::step(0.15))
-                .with_system(snake_movement.system().label(SnakeMovement::Movement))
-                .with_system(
-                    snake_eating
-                        .system()
-                        .label(SnakeMovement::Eating)
-                        .after(SnakeMovement::Movement),
-                )
-                .with_system(
-                    snake_growth
-                        .system()
-                        .label(SnakeMovement::Growing)
-                        .after(SnakeMovement::Eating),
-                )
-                .with_system(
-                    game_over
-                        .system()
-                        .after(SnakeMovement::Movement)
-                )
-        )
-        .add_system_set_to_stage(
-            CoreStage::PostUpdate,
-            SystemSet::new()
-                .with_system(position_translation.system())
-                .with_system(size_scaling.system()),
-        )
-        .add_system_set(
-            SystemSet::new()
-                .with_run_criteria(FixedTimestep::step(3.0))
-                .with_system(food_spawner.system()),
-        )
         .add_plugins(DefaultPlugins)
+        .add_plugin(SnakeGamePlugin::default())
         .run();
 }
-
-fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
-    commands.spawn_bundle(OrthographicCameraBundle::new_2d());
-    commands.insert_resource(Materials {
-        head_material: materials.add(get_bevy_color(184, 187, 38).into()),
-        food_material: materials.add(get_bevy_color(251, 73, 52).into()),
-        body_material: materials.add(get_bevy_color(152, 151, 26).into()),
-    })
-}
-
-fn spawn_snake(
-    mut commands: Commands,
-    mut segments: ResMut<SnakeSegments>,
-    materials: Res<Materials>,
-) {
-    segments.0 = vec![
-        commands
-            .spawn_bundle(SpriteBundle {
-                material: materials.head_material.clone(),
-                sprite: Sprite::new(Vec2::new(10.0, 10.0)),
-                ..Default::default()
-            })
-            .insert(SnakeHead {
-                direction: Direction::Up,
-            })
-            .insert(Size::square(0.8))
-            .insert(Position { x: 3, y: 3 })
-            .id(),
-        spawn_segment(commands, &materials.body_material, Position { x: 3, y: 2 }),
-    ];
-}
-
-fn food_spawner(mut commands: Commands, materials: Res<Materials>) {
-    commands
-        .spawn_bundle(SpriteBundle {
-            material: materials.food_material.clone(),
-            ..Default::default()
-        })
-        .insert(Food)
-        .insert(Position {
-            x: (random::<f32>() * ARENA_WIDTH as f32) as i32,
-            y: (random::<f32>() * ARENA_HEIGHT as f32) as i32,
-        })
-        .insert(Size::square(0.5));
-}
-
-fn spawn_segment(
-    mut commands: Commands,
-    material: &Handle<ColorMaterial>,
-    position: Position,
-) -> Entity {
-    commands
-        .spawn_bundle(SpriteBundle {
-            material: material.clone(),
-            ..Default::default()
-        })
-        .insert(SnakeBody)
-        .insert(position)
-        .insert(Size::square(0.65))
-        .id()
-}
-
-fn snake_movement(
-    segments: ResMut<SnakeSegments>,
-    mut heads: Query<(Entity, &SnakeHead)>,
-    mut positions: Query<&mut Position>,
-    mut last_tail_position: ResMut<LastTailPosition>,
-    mut game_over_writer: EventWriter<GameOverEvent>,
-) {
-    let width = ARENA_WIDTH as i32;
-    let height = ARENA_HEIGHT as i32;
-
-    for (head_entity, head) in heads.iter_mut() {
-        let segment_positions = segments
-            .0
-            .iter()
-            .map(|e| *positions.get_mut(*e).unwrap())
-            .collect::<Vec<Position>>();
-
-        let mut head_pos = positions.get_mut(head_entity).unwrap();
-        match &head.direction {
-            Direction::Left => {
-                head_pos.x = (((head_pos.x - 1) % width) + width) % width;
-            }
-            Direction::Right => {
-                head_pos.x = (head_pos.x + 1) % width;
-            }
-            Direction::Up => {
-                head_pos.y = (head_pos.y + 1) % height;
-            }
-            Direction::Down => {
-                head_pos.y = (((head_pos.y - 1) % height) + height) % height;
-            }
-        }
-
-        if segment_positions.contains(&head_pos) {
-            game_over_writer.send(GameOverEvent);
-        }
-
-        segment_positions
-            .iter()
-            .zip(segments.0.iter().skip(1))
-            .for_each(|(pos, segment)| {
-                *positions.get_mut(*segment).unwrap() = *pos;
-            });
-
-        last_tail_position.0 = Some(*segment_positions.last().unwrap());
-    }
-}
-
-fn snake_movement_input(keyboard_input: Res<Input<KeyCode>>, mut heads: Query<&mut SnakeHead>) {
-    for mut head in heads.iter_mut() {
-        if keyboard_input.pressed(KeyCode::Left) && head.direction != Direction::Right {
-            head.direction = Direction::Left;
-        }
-        if keyboard_input.pressed(KeyCode::Right) && head.direction != Direction::Left {
-            head.direction = Direction::Right;
-        }
-        if keyboard_input.pressed(KeyCode::Up) && head.direction != Direction::Down {
-            head.direction = Direction::Up;
-        }
-        if keyboard_input.pressed(KeyCode::Down) && head.direction != Direction::Up {
-            head.direction = Direction::Down;
-        }
-    }
-}
-
-fn snake_eating(
-    mut commands: Commands,
-    mut growth_writer: EventWriter<GrowthEvent>,
-    food_positions: Query<(Entity, &Position), With<Food>>,
-    head_positions: Query<&Position, With<SnakeHead>>,
-) {
-    for head_pos in head_positions.iter() {
-        for (ent, food_pos) in food_positions.iter() {
-            if food_pos == head_pos {
-                commands.entity(ent).despawn();
-                growth_writer.send(GrowthEvent);
-            }
-        }
-    }
-}
-
-fn snake_growth(
-    commands: Commands,
-    last_tail_position: Res<LastTailPosition>,
-    mut segments: ResMut<SnakeSegments>,
-    mut growth_reader: EventReader<GrowthEvent>,
-    materials: Res<Materials>,
-) {
-    if growth_reader.iter().next().is_some() {
-        segments.0.push(spawn_segment(
-            commands,
-            &materials.body_material,
-            last_tail_position.0.unwrap(),
-        ))
-    }
-}
-
-fn game_over(
-    mut commands: Commands,
-    mut reader: EventReader<GameOverEvent>,
-    materials: Res<Materials>,
-    segments_res: ResMut<SnakeSegments>,
-    food: Query<Entity, With<Food>>,
-    segments: Query<Entity, With<SnakeBody>>,
-    snake_head: Query<Entity, With<SnakeHead>>,
-){
-    if reader.iter().next().is_some(){
-        for ent in food.iter().chain(segments.iter()) {
-            commands.entity(ent).despawn();
-        }
-
-        commands.entity(snake_head.iter().next().unwrap()).despawn();
-        spawn_snake(commands, segments_res, materials);
-    }
-}
-
-fn size_scaling(windows: Res<Windows>, mut q: Query<(&Size, &mut Sprite)>) {
-    let window = windows.get_primary().unwrap();
-    for (sprite_size, mut sprite) in q.iter_mut() {
-        sprite.size = Vec2::new(
-            sprite_size.width / ARENA_WIDTH as f32 * window.width() as f32,
-            sprite_size.height / ARENA_HEIGHT as f32 * window.height() as f32,
-        );
-    }
-}
-
-fn position_translation(windows: Res<Windows>, mut q: Query<(&Position, &mut Transform)>) {
-    fn convert(pos: f32, bound_window: f32, bound_game: f32) -> f32 {
-        let tile_size = bound_window / bound_game;
-        pos / bound_game * bound_window - (bound_window / 2.) + (tile_size / 2.)
-    }
-    let window = windows.get_primary().unwrap();
-    for (pos, mut transform) in q.iter_mut() {
-        transform.translation = Vec3::new(
-            convert(pos.x as f32, window.width() as f32, ARENA_WIDTH as f32),
-            convert(pos.y as f32, window.height() as f32, ARENA_HEIGHT as f32),
-            0.,
-        );
-    }
-}
-
-fn get_bevy_color(r: u8, g: u8, b: u8) -> Color {
-    Color::rgb(r as f32 / 255., g as f32 / 255., b as f32 / 255.)
-}
\ No newline at end of file
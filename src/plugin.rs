@@ -0,0 +1,91 @@
+use bevy::core::FixedTimestep;
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::systems::*;
+
+#[derive(SystemLabel, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum SnakeMovement {
+    Input,
+    Movement,
+    Eating,
+    Growing,
+}
+
+/// Drops the snake minigame into any Bevy `App` with a single `add_plugin` call.
+///
+/// Arena size and the fixed-timestep rates for movement and food spawning are
+/// configured on the plugin itself rather than hard-coded. The settings are
+/// stored in a single `GameRules` resource, so an `App` holds one snake game;
+/// adding the plugin a second time replaces the first one's rules.
+pub struct SnakeGamePlugin {
+    pub arena_width: u32,
+    pub arena_height: u32,
+    pub wrap_walls: bool,
+    pub movement_step: f64,
+    pub food_spawn_step: f64,
+}
+
+impl Default for SnakeGamePlugin {
+    fn default() -> Self {
+        SnakeGamePlugin {
+            arena_width: 10,
+            arena_height: 10,
+            wrap_walls: true,
+            movement_step: 0.15,
+            food_spawn_step: 3.0,
+        }
+    }
+}
+
+impl Plugin for SnakeGamePlugin {
+    fn build(&self, app: &mut AppBuilder) {
+        app.insert_resource(GameRules {
+            wrap_walls: self.wrap_walls,
+            arena_width: self.arena_width,
+            arena_height: self.arena_height,
+        })
+        .insert_resource(LastTailPosition::default())
+        .insert_resource(SnakeSegments::default())
+        .add_event::<GrowthEvent>()
+        .add_event::<GameOverEvent>()
+        .add_event::<GameWonEvent>()
+        .add_startup_system(setup.system())
+        .add_startup_stage("game_setup", SystemStage::single(spawn_snake.system()))
+        .add_system(
+            snake_movement_input
+                .system()
+                .label(SnakeMovement::Input)
+                .before(SnakeMovement::Movement),
+        )
+        .add_system_set(
+            SystemSet::new()
+                .with_run_criteria(FixedTimestep::step(self.movement_step))
+                .with_system(snake_movement.system().label(SnakeMovement::Movement))
+                .with_system(
+                    snake_eating
+                        .system()
+                        .label(SnakeMovement::Eating)
+                        .after(SnakeMovement::Movement),
+                )
+                .with_system(
+                    snake_growth
+                        .system()
+                        .label(SnakeMovement::Growing)
+                        .after(SnakeMovement::Eating),
+                )
+                .with_system(game_over.system().after(SnakeMovement::Movement)),
+        )
+        .add_system_set_to_stage(
+            CoreStage::PostUpdate,
+            SystemSet::new()
+                .with_system(position_translation.system())
+                .with_system(size_scaling.system()),
+        )
+        .add_system_set(
+            SystemSet::new()
+                .with_run_criteria(FixedTimestep::step(self.food_spawn_step))
+                .with_system(food_spawner.system()),
+        );
+    }
+}
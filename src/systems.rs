@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+use rand::prelude::random;
+
+use crate::components::*;
+
+pub fn setup(mut commands: Commands, mut materials: ResMut<Assets<ColorMaterial>>) {
+    commands.spawn_bundle(OrthographicCameraBundle::new_2d());
+    commands.insert_resource(Materials {
+        head_material: materials.add(get_bevy_color(184, 187, 38).into()),
+        food_material: materials.add(get_bevy_color(251, 73, 52).into()),
+        body_material: materials.add(get_bevy_color(152, 151, 26).into()),
+    })
+}
+
+pub fn spawn_snake(
+    mut commands: Commands,
+    mut segments: ResMut<SnakeSegments>,
+    materials: Res<Materials>,
+) {
+    segments.0 = vec![
+        commands
+            .spawn_bundle(SpriteBundle {
+                material: materials.head_material.clone(),
+                sprite: Sprite::new(Vec2::new(10.0, 10.0)),
+                ..Default::default()
+            })
+            .insert(SnakeHead {
+                direction: Direction::Up,
+                intention: Direction::Up,
+            })
+            .insert(Size::square(0.8))
+            .insert(Position { x: 3, y: 3 })
+            .id(),
+        spawn_segment(commands, &materials.body_material, Position { x: 3, y: 2 }),
+    ];
+}
+
+pub fn food_spawner(
+    mut commands: Commands,
+    materials: Res<Materials>,
+    rules: Res<GameRules>,
+    mut won_writer: EventWriter<GameWonEvent>,
+    snake_positions: Query<&Position, Or<(With<SnakeHead>, With<SnakeBody>)>>,
+    food_positions: Query<&Position, With<Food>>,
+) {
+    let occupied = snake_positions
+        .iter()
+        .chain(food_positions.iter())
+        .copied()
+        .collect::<std::collections::HashSet<Position>>();
+
+    let free_cells = (0..rules.arena_width as i32)
+        .flat_map(|x| (0..rules.arena_height as i32).map(move |y| Position { x, y }))
+        .filter(|pos| !occupied.contains(pos))
+        .collect::<Vec<Position>>();
+
+    let free_cell = match free_cells.get((random::<f32>() * free_cells.len() as f32) as usize) {
+        Some(pos) => *pos,
+        None => {
+            won_writer.send(GameWonEvent);
+            return;
+        }
+    };
+
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: materials.food_material.clone(),
+            ..Default::default()
+        })
+        .insert(Food)
+        .insert(free_cell)
+        .insert(Size::square(0.5));
+}
+
+fn spawn_segment(mut commands: Commands, material: &Handle<ColorMaterial>, position: Position) -> Entity {
+    commands
+        .spawn_bundle(SpriteBundle {
+            material: material.clone(),
+            ..Default::default()
+        })
+        .insert(SnakeBody)
+        .insert(position)
+        .insert(Size::square(0.65))
+        .id()
+}
+
+pub fn snake_movement(
+    segments: ResMut<SnakeSegments>,
+    rules: Res<GameRules>,
+    mut heads: Query<(Entity, &mut SnakeHead)>,
+    mut positions: Query<&mut Position>,
+    mut last_tail_position: ResMut<LastTailPosition>,
+    mut game_over_writer: EventWriter<GameOverEvent>,
+) {
+    let width = rules.arena_width as i32;
+    let height = rules.arena_height as i32;
+
+    for (head_entity, mut head) in heads.iter_mut() {
+        head.direction = head.intention;
+
+        let segment_positions = segments
+            .0
+            .iter()
+            .map(|e| *positions.get_mut(*e).unwrap())
+            .collect::<Vec<Position>>();
+
+        let mut head_pos = positions.get_mut(head_entity).unwrap();
+        let (mut new_x, mut new_y) = (head_pos.x, head_pos.y);
+        match &head.direction {
+            Direction::Left => new_x -= 1,
+            Direction::Right => new_x += 1,
+            Direction::Up => new_y += 1,
+            Direction::Down => new_y -= 1,
+        }
+
+        if rules.wrap_walls {
+            head_pos.x = ((new_x % width) + width) % width;
+            head_pos.y = ((new_y % height) + height) % height;
+        } else if new_x < 0 || new_x >= width || new_y < 0 || new_y >= height {
+            game_over_writer.send(GameOverEvent);
+            continue;
+        } else {
+            head_pos.x = new_x;
+            head_pos.y = new_y;
+        }
+
+        if segment_positions.contains(&head_pos) {
+            game_over_writer.send(GameOverEvent);
+        }
+
+        segment_positions
+            .iter()
+            .zip(segments.0.iter().skip(1))
+            .for_each(|(pos, segment)| {
+                *positions.get_mut(*segment).unwrap() = *pos;
+            });
+
+        last_tail_position.0 = Some(*segment_positions.last().unwrap());
+    }
+}
+
+pub fn snake_movement_input(keyboard_input: Res<Input<KeyCode>>, mut heads: Query<&mut SnakeHead>) {
+    for mut head in heads.iter_mut() {
+        let dir = if keyboard_input.pressed(KeyCode::Left) {
+            Direction::Left
+        } else if keyboard_input.pressed(KeyCode::Right) {
+            Direction::Right
+        } else if keyboard_input.pressed(KeyCode::Up) {
+            Direction::Up
+        } else if keyboard_input.pressed(KeyCode::Down) {
+            Direction::Down
+        } else {
+            head.intention
+        };
+
+        if dir != head.direction.opposite() {
+            head.intention = dir;
+        }
+    }
+}
+
+pub fn snake_eating(
+    mut commands: Commands,
+    mut growth_writer: EventWriter<GrowthEvent>,
+    food_positions: Query<(Entity, &Position), With<Food>>,
+    head_positions: Query<&Position, With<SnakeHead>>,
+) {
+    for head_pos in head_positions.iter() {
+        for (ent, food_pos) in food_positions.iter() {
+            if food_pos == head_pos {
+                commands.entity(ent).despawn();
+                growth_writer.send(GrowthEvent);
+            }
+        }
+    }
+}
+
+pub fn snake_growth(
+    commands: Commands,
+    last_tail_position: Res<LastTailPosition>,
+    mut segments: ResMut<SnakeSegments>,
+    mut growth_reader: EventReader<GrowthEvent>,
+    materials: Res<Materials>,
+) {
+    if growth_reader.iter().next().is_some() {
+        segments.0.push(spawn_segment(
+            commands,
+            &materials.body_material,
+            last_tail_position.0.unwrap(),
+        ))
+    }
+}
+
+pub fn game_over(
+    mut commands: Commands,
+    mut reader: EventReader<GameOverEvent>,
+    materials: Res<Materials>,
+    segments_res: ResMut<SnakeSegments>,
+    food: Query<Entity, With<Food>>,
+    segments: Query<Entity, With<SnakeBody>>,
+    snake_head: Query<Entity, With<SnakeHead>>,
+) {
+    if reader.iter().next().is_some() {
+        for ent in food.iter().chain(segments.iter()) {
+            commands.entity(ent).despawn();
+        }
+
+        commands.entity(snake_head.iter().next().unwrap()).despawn();
+        spawn_snake(commands, segments_res, materials);
+    }
+}
+
+pub fn size_scaling(rules: Res<GameRules>, windows: Res<Windows>, mut q: Query<(&Size, &mut Sprite)>) {
+    let window = windows.get_primary().unwrap();
+    for (sprite_size, mut sprite) in q.iter_mut() {
+        sprite.size = Vec2::new(
+            sprite_size.width / rules.arena_width as f32 * window.width() as f32,
+            sprite_size.height / rules.arena_height as f32 * window.height() as f32,
+        );
+    }
+}
+
+pub fn position_translation(
+    rules: Res<GameRules>,
+    windows: Res<Windows>,
+    mut q: Query<(&Position, &mut Transform)>,
+) {
+    fn convert(pos: f32, bound_window: f32, bound_game: f32) -> f32 {
+        let tile_size = bound_window / bound_game;
+        pos / bound_game * bound_window - (bound_window / 2.) + (tile_size / 2.)
+    }
+    let window = windows.get_primary().unwrap();
+    for (pos, mut transform) in q.iter_mut() {
+        transform.translation = Vec3::new(
+            convert(pos.x as f32, window.width() as f32, rules.arena_width as f32),
+            convert(pos.y as f32, window.height() as f32, rules.arena_height as f32),
+            0.,
+        );
+    }
+}
+
+pub fn get_bevy_color(r: u8, g: u8, b: u8) -> Color {
+    Color::rgb(r as f32 / 255., g as f32 / 255., b as f32 / 255.)
+}